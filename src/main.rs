@@ -1,5 +1,5 @@
 use anyhow::{anyhow, Context, Result};
-use global_hotkey::hotkey::HotKey;
+use global_hotkey::hotkey::{HotKey, Modifiers};
 use global_hotkey::{GlobalHotKeyEvent, GlobalHotKeyManager, HotKeyState};
 use log::{error, info, LevelFilter};
 use notify::{recommended_watcher, Event, RecursiveMode, Watcher};
@@ -10,20 +10,220 @@ use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::time::{Duration, Instant};
 
-use winit::event_loop::{ControlFlow, EventLoop, ActiveEventLoop};
+use winit::event_loop::{ControlFlow, EventLoop, ActiveEventLoop, EventLoopProxy};
 use winit::application::ApplicationHandler;
-use tray_icon::{menu::{Menu, MenuItem, MenuEvent, MenuId}, TrayIconBuilder, TrayIconEvent, Icon};
+use tray_icon::{menu::{CheckMenuItem, Menu, MenuItem, MenuEvent, MenuId}, TrayIcon, TrayIconBuilder, TrayIconEvent, Icon};
+use notify_rust::Notification;
+
+const APP_NAME: &str = "Windows Shortcuts";
 
 #[derive(Debug, Deserialize, Clone)]
 struct Config {
+    #[serde(default)]
+    settings: Settings,
     hotkeys: Vec<HotkeyConfig>,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+struct Settings {
+    #[serde(default = "default_true")]
+    create_startup_shortcut: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            create_startup_shortcut: true,
+        }
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
 #[derive(Debug, Deserialize, Clone)]
 struct HotkeyConfig {
     shortcut: String,
-    path: String,
+    #[serde(default = "default_true")]
+    enabled: bool,
+    #[serde(flatten)]
+    action: HotkeyAction,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+enum HotkeyAction {
+    /// Open a file, folder, or URL with the OS default handler.
+    Launch { path: String },
+    /// Open a URL in the default browser.
+    Url { path: String },
+    /// Run a program with optional arguments.
+    Command {
+        program: String,
+        #[serde(default)]
+        args: Vec<String>,
+    },
+    /// Raise the window of an already-running program, or launch it if it isn't running.
+    FocusOrLaunch { path: String },
+}
+
+impl HotkeyAction {
+    fn run(&self) -> Result<()> {
+        match self {
+            HotkeyAction::Launch { path } | HotkeyAction::Url { path } => {
+                open::that(path).with_context(|| format!("Failed to open '{}'", path))
+            }
+            HotkeyAction::Command { program, args } => std::process::Command::new(program)
+                .args(args)
+                .spawn()
+                .map(|_| ())
+                .with_context(|| format!("Failed to run command '{}'", program)),
+            HotkeyAction::FocusOrLaunch { path } => focus_or_launch(path),
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn focus_or_launch(path: &str) -> Result<()> {
+    use windows_sys::Win32::Foundation::{CloseHandle, BOOL, HWND, LPARAM, MAX_PATH};
+    use windows_sys::Win32::System::Threading::{
+        OpenProcess, QueryFullProcessImageNameW, PROCESS_QUERY_LIMITED_INFORMATION,
+    };
+    use windows_sys::Win32::UI::WindowsAndMessaging::{
+        EnumWindows, GetWindowThreadProcessId, IsWindowVisible, SetForegroundWindow, ShowWindow,
+        SW_RESTORE,
+    };
+
+    struct EnumState {
+        // Windows paths are case-insensitive, so the target is kept lowercase (with forward
+        // slashes turned into backslashes) and compared against a lowercased image path below.
+        target: String,
+        found: HWND,
+    }
+
+    unsafe extern "system" fn enum_proc(hwnd: HWND, lparam: LPARAM) -> BOOL {
+        let state = &mut *(lparam as *mut EnumState);
+        if IsWindowVisible(hwnd) == 0 {
+            return 1;
+        }
+
+        let mut pid: u32 = 0;
+        GetWindowThreadProcessId(hwnd, &mut pid);
+        if pid == 0 {
+            return 1;
+        }
+
+        let process = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, 0, pid);
+        if process == 0 {
+            return 1;
+        }
+
+        let mut buffer = [0u16; MAX_PATH as usize];
+        let mut size = buffer.len() as u32;
+        let ok = QueryFullProcessImageNameW(process, 0, buffer.as_mut_ptr(), &mut size);
+        let mut matched = false;
+        if ok != 0 {
+            let exe_path = String::from_utf16_lossy(&buffer[..size as usize]).to_lowercase();
+            matched = exe_path.ends_with(&state.target);
+        }
+        CloseHandle(process);
+
+        if matched {
+            state.found = hwnd;
+            return 0;
+        }
+        1
+    }
+
+    let mut state = EnumState {
+        target: path.replace('/', "\\").to_lowercase(),
+        found: 0,
+    };
+
+    unsafe {
+        EnumWindows(Some(enum_proc), &mut state as *mut _ as isize);
+        if state.found != 0 {
+            ShowWindow(state.found, SW_RESTORE);
+            SetForegroundWindow(state.found);
+            return Ok(());
+        }
+    }
+
+    info!("No running window found for '{}', launching instead.", path);
+    open::that(path).with_context(|| format!("Failed to launch '{}'", path))
+}
+
+#[cfg(not(target_os = "windows"))]
+fn focus_or_launch(path: &str) -> Result<()> {
+    info!("focus-or-launch is only supported on Windows, launching '{}' instead.", path);
+    open::that(path).with_context(|| format!("Failed to launch '{}'", path))
+}
+
+/// A node in the tree of registered hotkeys. A `shortcut` like `"Ctrl+K, Ctrl+O"` is a chord:
+/// the leading accelerator is registered up front, and firing it walks one step down the tree.
+/// Only the next step's accelerators get registered with the OS, and only while that step is
+/// pending, so two chords can reuse the same second key without colliding.
+#[derive(Debug, Clone)]
+enum ChordStep {
+    Action(HotkeyAction),
+    Continuation(HashMap<u32, (HotKey, ChordStep)>),
+}
+
+const CHORD_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// The set of accelerators registered after a leading chord key fired, waiting for the next
+/// key of the sequence (or `CHORD_TIMEOUT`) before being unregistered again.
+struct PendingChord {
+    next: HashMap<u32, (HotKey, ChordStep)>,
+    deadline: Instant,
+}
+
+/// The lock keys whose live toggle state X11/Wayland bakes into the modifier mask reported at
+/// grab time. A registration made with, say, `Ctrl+Shift` only matches while every lock key
+/// happens to be off; OR-ing each of these in turn produces the sibling registrations needed so
+/// the accelerator still fires no matter which lock keys are toggled on when it's pressed.
+const LOCK_MODIFIER_BITS: [Modifiers; 3] = [
+    Modifiers::CAPS_LOCK,
+    Modifiers::NUM_LOCK,
+    Modifiers::SCROLL_LOCK,
+];
+
+/// Returns `hotkey` plus one copy per non-empty combination of `LOCK_MODIFIER_BITS` OR'd into
+/// its modifiers, so registering all of them makes the accelerator lock-key-insensitive.
+fn lock_insensitive_variants(hotkey: HotKey) -> Vec<HotKey> {
+    (0..(1u8 << LOCK_MODIFIER_BITS.len()))
+        .map(|combo| {
+            let mut mods = hotkey.mods;
+            for (bit, lock_modifier) in LOCK_MODIFIER_BITS.iter().enumerate() {
+                if combo & (1 << bit) != 0 {
+                    mods |= *lock_modifier;
+                }
+            }
+            HotKey::new(Some(mods), hotkey.key)
+        })
+        .collect()
+}
+
+/// Parses a `"Ctrl+K, Ctrl+O"`-style shortcut into its accelerator segments and builds the
+/// `ChordStep` tree for it, innermost (last) segment first. Every level is expanded into its
+/// lock-insensitive variants (see `lock_insensitive_variants`), all sharing the same next step.
+fn build_chord_step(
+    segments: &[&str],
+    action: &HotkeyAction,
+) -> Result<HashMap<u32, (HotKey, ChordStep)>> {
+    let hotkey = HotKey::from_str(segments[0].trim())?;
+    let step = if segments.len() == 1 {
+        ChordStep::Action(action.clone())
+    } else {
+        ChordStep::Continuation(build_chord_step(&segments[1..], action)?)
+    };
+    Ok(lock_insensitive_variants(hotkey)
+        .into_iter()
+        .map(|variant| (variant.id(), (variant, step.clone())))
+        .collect())
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -35,9 +235,96 @@ enum UserEvent {
 
 struct App {
     manager: GlobalHotKeyManager,
-    hotkeys_map: HashMap<u32, (HotKey, String)>,
+    hotkeys_map: HashMap<u32, (HotKey, ChordStep)>,
+    pending_chord: Option<PendingChord>,
     config_path: PathBuf,
+    proxy: EventLoopProxy<UserEvent>,
     quit_item_id: MenuId,
+    open_config_item_id: MenuId,
+    reload_item_id: MenuId,
+    pause_item: CheckMenuItem,
+    paused: bool,
+    tray_icon: TrayIcon,
+    config_errors: Vec<String>,
+}
+
+impl App {
+    /// Drops back to the idle state, unregistering whatever second-key accelerators were
+    /// registered for the chord that just completed, timed out, or got interrupted.
+    fn clear_pending_chord(&mut self) {
+        if let Some(pending) = self.pending_chord.take() {
+            let keys: Vec<HotKey> = pending.next.values().map(|(k, _)| *k).collect();
+            if let Err(e) = self.manager.unregister_all(&keys) {
+                error!("Failed to unregister chord continuation keys: {}", e);
+            }
+        }
+    }
+
+    /// Looks up `id` in the top-level hotkey map and either runs its action or, if `id` is the
+    /// leading key of a chord, enters the pending-chord state awaiting the next key.
+    fn dispatch_hotkey(&mut self, id: u32) {
+        if let Some((_, step)) = self.hotkeys_map.get(&id) {
+            match step {
+                ChordStep::Action(action) => {
+                    info!("Hotkey {} pressed, running action: {:?}", id, action);
+                    if let Err(e) = action.run() {
+                        error!("Failed to run action for hotkey {}: {}", id, e);
+                    }
+                }
+                ChordStep::Continuation(next) => {
+                    info!("Hotkey {} pressed, awaiting chord continuation.", id);
+                    self.register_pending_chord(next.clone());
+                }
+            }
+        }
+    }
+
+    /// Registers every accelerator in `next` with the OS and enters the pending-chord state,
+    /// giving the user `CHORD_TIMEOUT` to press one of them before it's unregistered again.
+    fn register_pending_chord(&mut self, next: HashMap<u32, (HotKey, ChordStep)>) {
+        let mut errors = Vec::new();
+        for (hotkey, _) in next.values() {
+            if let Err(e) = self.manager.register(*hotkey) {
+                let msg = format!("Failed to register chord continuation key: {}", e);
+                error!("{}", msg);
+                errors.push(msg);
+            }
+        }
+        if !errors.is_empty() {
+            self.report_reload_errors(errors);
+        }
+        self.pending_chord = Some(PendingChord {
+            next,
+            deadline: Instant::now() + CHORD_TIMEOUT,
+        });
+    }
+
+    /// Records `errors` (from the latest reload, or from registering a chord's continuation
+    /// keys) in place of the previous ones, reflects them in the tray tooltip, and pops a
+    /// notification so a mistake in `config.toml` doesn't go unnoticed.
+    fn report_reload_errors(&mut self, errors: Vec<String>) {
+        self.config_errors = errors;
+
+        let tooltip = if self.config_errors.is_empty() {
+            APP_NAME.to_string()
+        } else {
+            format!("{} — {} error(s)", APP_NAME, self.config_errors.len())
+        };
+        if let Err(e) = self.tray_icon.set_tooltip(Some(&tooltip)) {
+            error!("Failed to update tray tooltip: {}", e);
+        }
+
+        if !self.config_errors.is_empty() {
+            let body = self.config_errors.join("\n");
+            if let Err(e) = Notification::new()
+                .summary(&format!("{} — config errors", APP_NAME))
+                .body(&body)
+                .show()
+            {
+                error!("Failed to show config error notification: {}", e);
+            }
+        }
+    }
 }
 
 impl ApplicationHandler<UserEvent> for App {
@@ -45,6 +332,7 @@ impl ApplicationHandler<UserEvent> for App {
         match event {
             UserEvent::ConfigChanged => {
                 info!("Config file changed. Reloading hotkeys...");
+                self.clear_pending_chord();
                 let keys_to_unregister: Vec<HotKey> = self.hotkeys_map.values().map(|(k, _)| *k).collect();
                 if !keys_to_unregister.is_empty() {
                     if let Err(e) = self.manager.unregister_all(&keys_to_unregister) {
@@ -52,8 +340,20 @@ impl ApplicationHandler<UserEvent> for App {
                     }
                 }
                 self.hotkeys_map.clear();
-                if let Err(e) = load_and_register_hotkeys(&self.manager, &mut self.hotkeys_map, &self.config_path) {
-                    error!("Failed to reload and register hotkeys: {}", e);
+                if self.paused {
+                    info!("Hotkeys are paused, not re-registering until resumed.");
+                } else {
+                    match load_or_create_config(&self.config_path) {
+                        Ok(config) => {
+                            let errors =
+                                load_and_register_hotkeys(&self.manager, &mut self.hotkeys_map, &config);
+                            self.report_reload_errors(errors);
+                        }
+                        Err(e) => {
+                            error!("Failed to reload config: {}", e);
+                            self.report_reload_errors(vec![e.to_string()]);
+                        }
+                    }
                 }
             }
         }
@@ -74,15 +374,41 @@ impl ApplicationHandler<UserEvent> for App {
     }
 
     fn new_events(&mut self, event_loop: &ActiveEventLoop, _cause: winit::event::StartCause) {
-        event_loop.set_control_flow(ControlFlow::Wait);
+        if let Some(pending) = &self.pending_chord {
+            if Instant::now() >= pending.deadline {
+                info!("Chord timed out waiting for the next key, resetting.");
+                self.clear_pending_chord();
+            }
+        }
 
         if let Ok(event) = GlobalHotKeyEvent::receiver().try_recv() {
             if event.state == HotKeyState::Pressed {
-                if let Some((_, path)) = self.hotkeys_map.get(&event.id) {
-                    info!("Hotkey {} pressed, opening: {}", event.id, path);
-                    if let Err(e) = open::that(path) {
-                        error!("Failed to open path \'{}\': {}", path, e);
+                if let Some(pending) = self.pending_chord.take() {
+                    let keys: Vec<HotKey> = pending.next.values().map(|(k, _)| *k).collect();
+                    if let Err(e) = self.manager.unregister_all(&keys) {
+                        error!("Failed to unregister chord continuation keys: {}", e);
                     }
+                    match pending.next.get(&event.id) {
+                        Some((_, ChordStep::Action(action))) => {
+                            info!("Chord completed, running action: {:?}", action);
+                            if let Err(e) = action.run() {
+                                error!("Failed to run action for chord: {}", e);
+                            }
+                        }
+                        Some((_, ChordStep::Continuation(next))) => {
+                            info!("Chord advanced, awaiting next key.");
+                            self.register_pending_chord(next.clone());
+                        }
+                        None => {
+                            // Not a continuation key: the leading-level hotkeys stayed
+                            // registered the whole time the chord was pending, so fall back to
+                            // the normal dispatch instead of swallowing the press.
+                            info!("Non-matching key during chord, resetting.");
+                            self.dispatch_hotkey(event.id);
+                        }
+                    }
+                } else {
+                    self.dispatch_hotkey(event.id);
                 }
             }
         }
@@ -98,8 +424,51 @@ impl ApplicationHandler<UserEvent> for App {
             if *event.id() == self.quit_item_id {
                 info!("Quit item clicked, exiting application.");
                 event_loop.exit();
+            } else if *event.id() == self.open_config_item_id {
+                info!("Open Config clicked, opening {:?}", self.config_path);
+                if let Err(e) = open::that(&self.config_path) {
+                    error!("Failed to open config path {:?}: {}", self.config_path, e);
+                }
+            } else if *event.id() == self.reload_item_id {
+                info!("Reload Now clicked, requesting config reload.");
+                if let Err(e) = self.proxy.send_event(UserEvent::ConfigChanged) {
+                    error!("Failed to dispatch reload event: {}", e);
+                }
+            } else if *event.id() == self.pause_item.id() {
+                self.paused = self.pause_item.is_checked();
+                if self.paused {
+                    info!("Pause Hotkeys enabled, unregistering all hotkeys.");
+                    self.clear_pending_chord();
+                    let keys_to_unregister: Vec<HotKey> = self.hotkeys_map.values().map(|(k, _)| *k).collect();
+                    if !keys_to_unregister.is_empty() {
+                        if let Err(e) = self.manager.unregister_all(&keys_to_unregister) {
+                            error!("Failed to unregister all hotkeys: {}", e);
+                        }
+                    }
+                    self.hotkeys_map.clear();
+                } else {
+                    info!("Pause Hotkeys disabled, re-registering hotkeys.");
+                    match load_or_create_config(&self.config_path) {
+                        Ok(config) => {
+                            let errors =
+                                load_and_register_hotkeys(&self.manager, &mut self.hotkeys_map, &config);
+                            self.report_reload_errors(errors);
+                        }
+                        Err(e) => {
+                            error!("Failed to re-register hotkeys: {}", e);
+                            self.report_reload_errors(vec![e.to_string()]);
+                        }
+                    }
+                }
             }
         }
+
+        // While a chord is pending we need to wake up on the timeout even if no further
+        // input arrives; otherwise it's fine to sleep until the next external event.
+        match &self.pending_chord {
+            Some(pending) => event_loop.set_control_flow(ControlFlow::WaitUntil(pending.deadline)),
+            None => event_loop.set_control_flow(ControlFlow::Wait),
+        }
     }
 
     fn about_to_wait(&mut self, _event_loop: &ActiveEventLoop) {
@@ -118,19 +487,35 @@ fn main() -> Result<()> {
         .init()?;
     info!("Starting application");
 
-    create_startup_shortcut().context("Failed to create startup shortcut")?;
+    let config_path = get_config_path()?;
+    let config = load_or_create_config(&config_path)?;
+
+    if config.settings.create_startup_shortcut {
+        create_startup_shortcut().context("Failed to create startup shortcut")?;
+    } else {
+        info!("create_startup_shortcut disabled in [settings], skipping.");
+    }
 
     let event_loop = EventLoop::<UserEvent>::with_user_event().build()?;
     let manager = GlobalHotKeyManager::new().context("Failed to create hotkey manager")?;
-    let mut hotkeys_map: HashMap<u32, (HotKey, String)> = HashMap::new();
+    let mut hotkeys_map: HashMap<u32, (HotKey, ChordStep)> = HashMap::new();
 
-    let config_path = get_config_path()?;
-    load_and_register_hotkeys(&manager, &mut hotkeys_map, &config_path)?;
+    let config_errors = load_and_register_hotkeys(&manager, &mut hotkeys_map, &config);
 
     let tray_menu = Menu::new();
+    let open_config_item = MenuItem::new("Open Config", true, None);
+    let open_config_item_id = open_config_item.id();
+    let reload_item = MenuItem::new("Reload Now", true, None);
+    let reload_item_id = reload_item.id();
+    let pause_item = CheckMenuItem::new("Pause Hotkeys", true, false, None);
     let quit_item = MenuItem::new("Quit", true, None);
     let quit_item_id = quit_item.id();
-    let _ = tray_menu.append_items(&[&quit_item]);
+    let _ = tray_menu.append_items(&[
+        &open_config_item,
+        &reload_item,
+        &pause_item,
+        &quit_item,
+    ]);
 
     let icon_bytes = include_bytes!("../assets/icon.png");
     
@@ -144,26 +529,37 @@ fn main() -> Result<()> {
     };
     let icon = tray_icon::Icon::from_rgba(icon_rgba, icon_width, icon_height).expect("Failed to open icon");
 
-    let _tray_icon = Some(TrayIconBuilder::new()
+    let tray_icon = TrayIconBuilder::new()
         .with_menu(Box::new(tray_menu))
-        .with_tooltip("Windows Shortcuts")
+        .with_tooltip(APP_NAME)
         .with_icon(icon)
-        .build()?);
+        .build()?;
 
     info!("Listening for hotkeys and config changes...");
 
+    let proxy = event_loop.create_proxy();
+
     let mut app = App {
         manager,
         hotkeys_map,
+        pending_chord: None,
         config_path: config_path.clone(),
+        proxy: proxy.clone(),
         quit_item_id: quit_item_id.clone(),
+        open_config_item_id: open_config_item_id.clone(),
+        reload_item_id: reload_item_id.clone(),
+        pause_item,
+        paused: false,
+        tray_icon,
+        config_errors: Vec::new(),
     };
+    app.report_reload_errors(config_errors);
 
-    let proxy = event_loop.create_proxy();
+    let watcher_proxy = proxy.clone();
     let mut watcher = recommended_watcher(move |res| {
         if let Ok(Event { kind, .. }) = res {
             if kind.is_modify() || kind.is_create() {
-                proxy.send_event(UserEvent::ConfigChanged).unwrap();
+                watcher_proxy.send_event(UserEvent::ConfigChanged).unwrap();
             }
         }
     })?;
@@ -174,28 +570,50 @@ fn main() -> Result<()> {
     Ok(())
 }
 
+/// Registers every enabled hotkey's leading accelerator (and its lock-insensitive variants, see
+/// `lock_insensitive_variants`) from an already-loaded `config`, returning a human-readable
+/// message per entry that failed to parse and per variant that failed to register, instead of
+/// aborting the whole batch. A
+/// `shortcut` of `"Ctrl+K, Ctrl+O"` registers only `Ctrl+K`; the rest of the chord is registered
+/// on demand once its prefix fires (see `App::register_pending_chord`).
 fn load_and_register_hotkeys(
     manager: &GlobalHotKeyManager,
-    hotkeys_map: &mut HashMap<u32, (HotKey, String)>,
-    config_path: &Path,
-) -> Result<()> {
-    let config = load_or_create_config(config_path)?;
-    for hotkey_config in config.hotkeys {
-        match HotKey::from_str(&hotkey_config.shortcut) {
-            Ok(hotkey) => {
-                if let Err(e) = manager.register(hotkey) {
-                    error!("Failed to register hotkey for shortcut \'{}\": {}", hotkey_config.shortcut, e);
-                    continue;
+    hotkeys_map: &mut HashMap<u32, (HotKey, ChordStep)>,
+    config: &Config,
+) -> Vec<String> {
+    let mut errors = Vec::new();
+    for hotkey_config in &config.hotkeys {
+        if !hotkey_config.enabled {
+            info!("Skipping disabled hotkey: {}", hotkey_config.shortcut);
+            continue;
+        }
+        let segments: Vec<&str> = hotkey_config.shortcut.split(',').map(str::trim).collect();
+        match build_chord_step(&segments, &hotkey_config.action) {
+            Ok(variants) => {
+                let mut registered = 0;
+                for (id, (hotkey, step)) in variants {
+                    if let Err(e) = manager.register(hotkey) {
+                        let msg = format!("Failed to register '{}': {}", hotkey_config.shortcut, e);
+                        error!("{}", msg);
+                        errors.push(msg);
+                        continue;
+                    }
+                    hotkeys_map.insert(id, (hotkey, step));
+                    registered += 1;
                 }
-                hotkeys_map.insert(hotkey.id(), (hotkey, hotkey_config.path.clone()));
-                info!("Registered hotkey: {} for path {}", hotkey_config.shortcut, hotkey_config.path);
+                info!(
+                    "Registered hotkey: {} -> {:?} ({} lock-insensitive variant(s))",
+                    hotkey_config.shortcut, hotkey_config.action, registered
+                );
             }
             Err(e) => {
-                error!("Failed to parse shortcut \'{}\": {}", hotkey_config.shortcut, e);
+                let msg = format!("Failed to parse shortcut '{}': {}", hotkey_config.shortcut, e);
+                error!("{}", msg);
+                errors.push(msg);
             }
         }
     }
-    Ok(())
+    errors
 }
 
 fn get_config_path() -> Result<PathBuf> {
@@ -213,13 +631,29 @@ fn load_or_create_config(config_path: &Path) -> Result<Config> {
 # Keys can be A-Z, 0-9, F1-F12.
 # For file paths, it is recommended to use forward slashes (e.g., "C:/Users/YourUser/Documents/file.txt")
 # or double backslashes (e.g., "C:\\Users\\YourUser\\Documents\\file.txt").
+#
+# Each [[hotkeys]] entry needs a `type`:
+#   type = "launch"          -> path = "..."               (open with the OS default handler)
+#   type = "url"              -> path = "..."               (open in the default browser)
+#   type = "command"          -> program = "...", args = [...]
+#   type = "focus-or-launch"  -> path = "..."               (raise the window if already running, else launch)
+# Add `enabled = false` to a hotkey entry to keep it in the file without registering it.
+# A shortcut can also be a chord, e.g. "Ctrl+K, Ctrl+O" — press the first accelerator, then
+# the second within 1 second to trigger the action.
+
+[settings]
+# Set to false to stop the app from adding itself to the Windows Startup folder
+# (or, on Linux, the XDG autostart folder).
+create_startup_shortcut = true
 
 [[hotkeys]]
 shortcut = "Ctrl+Shift+A"
+type = "launch"
 path = "C:/Windows/System32/notepad.exe"
 
 [[hotkeys]]
 shortcut = "Ctrl+Shift+B"
+type = "url"
 path = "https://www.google.com"
 "#;
         fs::write(&config_path, default_config_content).context("Failed to write default config")?;
@@ -262,8 +696,36 @@ use shortcuts_rs::ShellLink;
     Ok(())
 }
 
+/// Registers the app with the desktop environment's autostart mechanism via the
+/// XDG Desktop Entry spec (`~/.config/autostart/*.desktop`), the Linux equivalent
+/// of dropping a `.lnk` into the Windows Startup folder.
 #[cfg(not(target_os = "windows"))]
 fn create_startup_shortcut() -> Result<()> {
-    info!("Startup shortcut creation is only supported on Windows.");
+    let app_exe = std::env::current_exe().context("Failed to get current executable path")?;
+    let app_name = app_exe
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .context("Failed to get app name")?;
+
+    let autostart_dir = dirs::config_dir()
+        .ok_or_else(|| anyhow!("Could not find config directory"))?
+        .join("autostart");
+    fs::create_dir_all(&autostart_dir).context("Failed to create autostart directory")?;
+
+    let desktop_entry_path = autostart_dir.join(format!("{}.desktop", app_name));
+
+    if desktop_entry_path.exists() {
+        info!("Autostart entry already exists at {:?}", desktop_entry_path);
+        return Ok(());
+    }
+
+    info!("Creating autostart entry at {:?}", desktop_entry_path);
+    let desktop_entry = format!(
+        "[Desktop Entry]\nType=Application\nName={}\nExec=\"{}\"\nX-GNOME-Autostart-enabled=true\n",
+        app_name,
+        app_exe.display(),
+    );
+    fs::write(&desktop_entry_path, desktop_entry).context("Failed to write autostart entry")?;
+
     Ok(())
 }